@@ -0,0 +1,49 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+#[cfg(windows)]
+mod windows;
+
+/// A node in the process tree rooted at a given pid.
+#[derive(Debug, Clone)]
+pub struct LocalProcessInfo {
+    pub pid: u32,
+    pub ppid: u32,
+    pub name: String,
+    /// The resolved `DOMAIN\user` account name owning the process, falling
+    /// back to the raw SID string when the account can't be resolved.
+    pub user: Option<String>,
+    pub executable: PathBuf,
+    pub cwd: PathBuf,
+    pub status: LocalProcessStatus,
+    pub argv: Vec<String>,
+    /// The process environment, as `KEY=VALUE` pairs, when available.
+    pub environ: Vec<(String, String)>,
+    pub start_time: SystemTime,
+    /// Cumulative CPU time spent in kernel mode.
+    pub cpu_kernel_time: Duration,
+    /// Cumulative CPU time spent in user mode.
+    pub cpu_user_time: Duration,
+    /// Working set size, in bytes.
+    pub memory_working_set: u64,
+    /// Private (non-shareable) committed memory, in bytes.
+    pub memory_private_bytes: u64,
+    /// Total bytes read from disk over the process's lifetime.
+    pub disk_read_bytes: u64,
+    /// Total bytes written to disk over the process's lifetime.
+    pub disk_write_bytes: u64,
+    pub children: HashMap<u32, LocalProcessInfo>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LocalProcessStatus {
+    Run,
+    Sleep,
+    Stop,
+    Zombie,
+    /// Every thread in the process is suspended (eg. a Ctrl-Z'd shell or a
+    /// frozen UWP app).
+    Suspended,
+    Unknown,
+}
@@ -5,24 +5,38 @@ impl LocalProcessInfo {
     pub fn with_root_pid(pid: u32) -> Option<Self> {
         use ntapi::ntpebteb::PEB;
         use ntapi::ntpsapi::{
-            NtQueryInformationProcess, ProcessBasicInformation, ProcessWow64Information,
+            NtQueryInformationProcess, NtQueryInformationThread, ProcessBasicInformation,
+            ProcessCommandLineInformation, ProcessWow64Information, ThreadSuspendCount,
             PROCESS_BASIC_INFORMATION,
         };
-        use ntapi::ntrtl::RTL_USER_PROCESS_PARAMETERS;
+        use ntapi::ntrtl::{RtlGetVersion, RTL_USER_PROCESS_PARAMETERS};
         use ntapi::ntwow64::RTL_USER_PROCESS_PARAMETERS32;
         use std::ffi::OsString;
         use std::mem::MaybeUninit;
         use std::os::windows::ffi::OsStringExt;
         use winapi::shared::minwindef::{FILETIME, HMODULE, LPVOID, MAX_PATH};
-        use winapi::shared::ntdef::{FALSE, NT_SUCCESS};
+        use winapi::shared::ntdef::{UNICODE_STRING, FALSE, NT_SUCCESS};
+        use winapi::shared::ntstatus::STATUS_INFO_LENGTH_MISMATCH;
+        use winapi::shared::sddl::ConvertSidToStringSidW;
         use winapi::um::handleapi::CloseHandle;
         use winapi::um::memoryapi::ReadProcessMemory;
-        use winapi::um::processthreadsapi::{GetProcessTimes, OpenProcess};
-        use winapi::um::psapi::{EnumProcessModulesEx, GetModuleFileNameExW, LIST_MODULES_ALL};
+        use winapi::um::processthreadsapi::{
+            GetProcessIoCounters, GetProcessTimes, OpenProcess, OpenProcessToken, OpenThread,
+            QueryFullProcessImageNameW,
+        };
+        use winapi::um::psapi::{
+            EnumProcessModulesEx, GetModuleFileNameExW, GetProcessMemoryInfo,
+            PROCESS_MEMORY_COUNTERS_EX, LIST_MODULES_ALL,
+        };
+        use winapi::um::securitybaseapi::GetTokenInformation;
         use winapi::um::shellapi::CommandLineToArgvW;
         use winapi::um::tlhelp32::*;
-        use winapi::um::winbase::LocalFree;
-        use winapi::um::winnt::{HANDLE, PROCESS_QUERY_INFORMATION, PROCESS_VM_READ};
+        use winapi::um::winbase::{LocalFree, LookupAccountSidW};
+        use winapi::um::winnt::{
+            TokenUser, HANDLE, IO_COUNTERS, OSVERSIONINFOW, PROCESS_QUERY_INFORMATION,
+            PROCESS_QUERY_LIMITED_INFORMATION, PROCESS_VM_READ, PSID,
+            THREAD_QUERY_LIMITED_INFORMATION, TOKEN_QUERY, TOKEN_USER,
+        };
 
         struct Snapshot(HANDLE);
 
@@ -75,9 +89,111 @@ impl LocalProcessInfo {
             }
         }
 
+        struct ThreadSnapshot(HANDLE);
+
+        impl ThreadSnapshot {
+            pub fn new() -> Option<Self> {
+                let handle = unsafe { CreateToolhelp32Snapshot(TH32CS_SNAPTHREAD, 0) };
+                if handle.is_null() {
+                    None
+                } else {
+                    Some(Self(handle))
+                }
+            }
+
+            pub fn iter(&self) -> ThreadIter {
+                ThreadIter {
+                    snapshot: &self,
+                    first: true,
+                }
+            }
+        }
+
+        impl Drop for ThreadSnapshot {
+            fn drop(&mut self) {
+                unsafe { CloseHandle(self.0) };
+            }
+        }
+
+        struct ThreadIter<'a> {
+            snapshot: &'a ThreadSnapshot,
+            first: bool,
+        }
+
+        impl<'a> Iterator for ThreadIter<'a> {
+            type Item = THREADENTRY32;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                let mut entry: THREADENTRY32 = unsafe { std::mem::zeroed() };
+                entry.dwSize = std::mem::size_of::<THREADENTRY32>() as _;
+                let res = if self.first {
+                    self.first = false;
+                    unsafe { Thread32First(self.snapshot.0, &mut entry) }
+                } else {
+                    unsafe { Thread32Next(self.snapshot.0, &mut entry) }
+                };
+                if res == 0 {
+                    None
+                } else {
+                    Some(entry)
+                }
+            }
+        }
+
+        // A process is considered suspended when every one of its threads
+        // has a non-zero suspend count; if no thread info is obtainable we
+        // fall back to reporting it as running. `threads` is the full,
+        // system-wide snapshot collected once up front (see `procs` below),
+        // so recursing over the process tree doesn't re-walk every thread on
+        // the machine for each node.
+        fn process_status(pid: u32, threads: &[THREADENTRY32]) -> LocalProcessStatus {
+            let mut total = 0;
+            let mut suspended = 0;
+            for entry in threads {
+                if entry.th32OwnerProcessID != pid {
+                    continue;
+                }
+                total += 1;
+
+                let handle = unsafe {
+                    OpenThread(THREAD_QUERY_LIMITED_INFORMATION, FALSE as _, entry.th32ThreadID)
+                };
+                if handle.is_null() {
+                    continue;
+                }
+                let mut count: u32 = 0;
+                let res = unsafe {
+                    NtQueryInformationThread(
+                        handle,
+                        ThreadSuspendCount,
+                        &mut count as *mut _ as _,
+                        std::mem::size_of::<u32>() as _,
+                        std::ptr::null_mut(),
+                    )
+                };
+                unsafe { CloseHandle(handle) };
+                if NT_SUCCESS(res) && count > 0 {
+                    suspended += 1;
+                }
+            }
+
+            if total > 0 && total == suspended {
+                LocalProcessStatus::Suspended
+            } else {
+                LocalProcessStatus::Run
+            }
+        }
+
         let snapshot = Snapshot::new()?;
         let procs: Vec<_> = snapshot.iter().collect();
 
+        // Collected once, up front, and shared across the recursive
+        // `build_proc` walk below, same as `procs` itself.
+        let threads: Vec<THREADENTRY32> = match ThreadSnapshot::new() {
+            Some(snapshot) => snapshot.iter().collect(),
+            None => vec![],
+        };
+
         fn wstr_to_path(slice: &[u16]) -> PathBuf {
             match slice.iter().position(|&c| c == 0) {
                 Some(nul) => OsString::from_wide(&slice[..nul]),
@@ -92,17 +208,135 @@ impl LocalProcessInfo {
         struct ProcParams {
             argv: Vec<String>,
             cwd: PathBuf,
+            environ: Vec<(String, String)>,
+        }
+
+        struct TokenHandle(HANDLE);
+
+        impl TokenHandle {
+            fn new(process: HANDLE) -> Option<Self> {
+                let mut token = std::ptr::null_mut();
+                let res = unsafe { OpenProcessToken(process, TOKEN_QUERY, &mut token) };
+                if res == 0 {
+                    return None;
+                }
+                Some(Self(token))
+            }
+
+            fn user_sid_buf(&self) -> Option<Vec<u8>> {
+                let mut needed = 0;
+                unsafe {
+                    GetTokenInformation(self.0, TokenUser, std::ptr::null_mut(), 0, &mut needed)
+                };
+                if needed == 0 {
+                    return None;
+                }
+                let mut buf = vec![0u8; needed as usize];
+                let res = unsafe {
+                    GetTokenInformation(
+                        self.0,
+                        TokenUser,
+                        buf.as_mut_ptr() as _,
+                        needed,
+                        &mut needed,
+                    )
+                };
+                if res == 0 {
+                    return None;
+                }
+                Some(buf)
+            }
+        }
+
+        impl Drop for TokenHandle {
+            fn drop(&mut self) {
+                unsafe { CloseHandle(self.0) };
+            }
+        }
+
+        fn sid_to_string(sid: PSID) -> Option<String> {
+            let mut buf = std::ptr::null_mut();
+            let res = unsafe { ConvertSidToStringSidW(sid, &mut buf) };
+            if res == 0 || buf.is_null() {
+                return None;
+            }
+            let len = unsafe { libc::wcslen(buf) };
+            let slice = unsafe { std::slice::from_raw_parts(buf, len) };
+            let s = wstr_to_string(slice);
+            unsafe { LocalFree(buf as _) };
+            Some(s)
         }
 
-        struct ProcHandle(HANDLE);
+        fn lookup_account_sid(sid: PSID) -> Option<String> {
+            let mut name_len = 0;
+            let mut domain_len = 0;
+            let mut sid_name_use = 0;
+            unsafe {
+                LookupAccountSidW(
+                    std::ptr::null(),
+                    sid,
+                    std::ptr::null_mut(),
+                    &mut name_len,
+                    std::ptr::null_mut(),
+                    &mut domain_len,
+                    &mut sid_name_use,
+                )
+            };
+            if name_len == 0 {
+                return None;
+            }
+            let mut name = vec![0u16; name_len as usize];
+            let mut domain = vec![0u16; domain_len as usize];
+            let res = unsafe {
+                LookupAccountSidW(
+                    std::ptr::null(),
+                    sid,
+                    name.as_mut_ptr(),
+                    &mut name_len,
+                    domain.as_mut_ptr(),
+                    &mut domain_len,
+                    &mut sid_name_use,
+                )
+            };
+            if res == 0 {
+                return None;
+            }
+            let domain = wstr_to_string(&domain);
+            let name = wstr_to_string(&name);
+            if domain.is_empty() {
+                Some(name)
+            } else {
+                Some(format!("{}\\{}", domain, name))
+            }
+        }
+
+        struct ProcHandle {
+            handle: HANDLE,
+            /// true if we could only obtain `PROCESS_QUERY_LIMITED_INFORMATION`
+            /// rights (eg. for an elevated/protected process), in which case
+            /// anything relying on `ReadProcessMemory` is skipped.
+            limited: bool,
+        }
         impl ProcHandle {
             fn new(pid: u32) -> Option<Self> {
-                let options = PROCESS_QUERY_INFORMATION | PROCESS_VM_READ;
-                let handle = unsafe { OpenProcess(options, FALSE as _, pid) };
+                let full = PROCESS_QUERY_INFORMATION | PROCESS_VM_READ;
+                let handle = unsafe { OpenProcess(full, FALSE as _, pid) };
+                if !handle.is_null() {
+                    return Some(Self {
+                        handle,
+                        limited: false,
+                    });
+                }
+
+                let handle =
+                    unsafe { OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, FALSE as _, pid) };
                 if handle.is_null() {
                     return None;
                 }
-                Some(Self(handle))
+                Some(Self {
+                    handle,
+                    limited: true,
+                })
             }
 
             fn hmodule(&self) -> Option<HMODULE> {
@@ -111,7 +345,7 @@ impl LocalProcessInfo {
                 let size = std::mem::size_of_val(&hmod);
                 let res = unsafe {
                     EnumProcessModulesEx(
-                        self.0,
+                        self.handle,
                         hmod.as_mut_ptr(),
                         size as _,
                         &mut needed,
@@ -126,10 +360,14 @@ impl LocalProcessInfo {
             }
 
             fn executable(&self) -> Option<PathBuf> {
+                if self.limited {
+                    return self.executable_via_image_name();
+                }
                 let hmod = self.hmodule()?;
                 let mut buf = [0u16; MAX_PATH + 1];
-                let res =
-                    unsafe { GetModuleFileNameExW(self.0, hmod, buf.as_mut_ptr(), buf.len() as _) };
+                let res = unsafe {
+                    GetModuleFileNameExW(self.handle, hmod, buf.as_mut_ptr(), buf.len() as _)
+                };
                 if res == 0 {
                     None
                 } else {
@@ -137,11 +375,26 @@ impl LocalProcessInfo {
                 }
             }
 
+            // Works with just PROCESS_QUERY_LIMITED_INFORMATION, unlike the
+            // module-enumeration path above.
+            fn executable_via_image_name(&self) -> Option<PathBuf> {
+                let mut buf = [0u16; MAX_PATH + 1];
+                let mut size = buf.len() as u32;
+                let res = unsafe {
+                    QueryFullProcessImageNameW(self.handle, 0, buf.as_mut_ptr(), &mut size)
+                };
+                if res == 0 {
+                    None
+                } else {
+                    Some(wstr_to_path(&buf[..size as usize]))
+                }
+            }
+
             fn get_peb32_addr(&self) -> Option<LPVOID> {
                 let mut peb32_addr = MaybeUninit::<LPVOID>::uninit();
                 let res = unsafe {
                     NtQueryInformationProcess(
-                        self.0,
+                        self.handle,
                         ProcessWow64Information,
                         peb32_addr.as_mut_ptr() as _,
                         std::mem::size_of::<LPVOID>() as _,
@@ -160,17 +413,92 @@ impl LocalProcessInfo {
             }
 
             fn get_params(&self) -> Option<ProcParams> {
-                match self.get_peb32_addr() {
-                    Some(peb32) => self.get_params_32(peb32),
-                    None => self.get_params_64(),
+                // The PEB walk requires PROCESS_VM_READ, which we don't have
+                // in limited mode; fall through to the NtQuery-only path.
+                let mut params = if self.limited {
+                    None
+                } else {
+                    match self.get_peb32_addr() {
+                        Some(peb32) => self.get_params_32(peb32),
+                        None => self.get_params_64(),
+                    }
+                };
+
+                // The PEB-walking paths above silently fail for protected or
+                // partially-paged processes. Prefer argv recovered via
+                // NtQueryInformationProcess(ProcessCommandLineInformation),
+                // which doesn't require reading the target's memory, so argv
+                // is populated in strictly more cases than the PEB walk alone.
+                if let Some(argv) = self.get_command_line_via_query() {
+                    match &mut params {
+                        Some(params) => params.argv = argv,
+                        None => {
+                            params = Some(ProcParams {
+                                argv,
+                                cwd: PathBuf::new(),
+                                environ: vec![],
+                            })
+                        }
+                    }
+                }
+
+                params
+            }
+
+            fn get_command_line_via_query(&self) -> Option<Vec<String>> {
+                if !os_supports_process_command_line_information() {
+                    return None;
+                }
+
+                let mut needed = 0u32;
+                let res = unsafe {
+                    NtQueryInformationProcess(
+                        self.handle,
+                        ProcessCommandLineInformation,
+                        std::ptr::null_mut(),
+                        0,
+                        &mut needed,
+                    )
+                };
+                if res != STATUS_INFO_LENGTH_MISMATCH || needed == 0 {
+                    return None;
+                }
+
+                let mut buf = vec![0u8; needed as usize];
+                let res = unsafe {
+                    NtQueryInformationProcess(
+                        self.handle,
+                        ProcessCommandLineInformation,
+                        buf.as_mut_ptr() as _,
+                        needed,
+                        &mut needed,
+                    )
+                };
+                if !NT_SUCCESS(res) {
+                    return None;
+                }
+
+                let unicode_string = buf.as_ptr() as *const UNICODE_STRING;
+                let len = unsafe { (*unicode_string).Length as usize / 2 };
+                let ptr = unsafe { (*unicode_string).Buffer };
+                if ptr.is_null() {
+                    return None;
                 }
+                // `Length` covers exactly the command line text, with no
+                // reserved trailing WCHAR in our allocation, so copy it into
+                // an owned buffer with an explicit NUL terminator before
+                // handing it to `cmd_line_to_argv` (which scans for NUL with
+                // no length bound via `CommandLineToArgvW`).
+                let mut cmdline = vec![0u16; len + 1];
+                unsafe { std::ptr::copy_nonoverlapping(ptr, cmdline.as_mut_ptr(), len) };
+                Some(cmd_line_to_argv(&cmdline))
             }
 
             fn get_basic_info(&self) -> Option<PROCESS_BASIC_INFORMATION> {
                 let mut info = MaybeUninit::<PROCESS_BASIC_INFORMATION>::uninit();
                 let res = unsafe {
                     NtQueryInformationProcess(
-                        self.0,
+                        self.handle,
                         ProcessBasicInformation,
                         info.as_mut_ptr() as _,
                         std::mem::size_of::<PROCESS_BASIC_INFORMATION>() as _,
@@ -188,7 +516,7 @@ impl LocalProcessInfo {
                 let mut data = MaybeUninit::<T>::uninit();
                 let res = unsafe {
                     ReadProcessMemory(
-                        self.0,
+                        self.handle,
                         addr as _,
                         data.as_mut_ptr() as _,
                         std::mem::size_of::<T>() as _,
@@ -223,10 +551,15 @@ impl LocalProcessInfo {
                     params.CurrentDirectory.DosPath.Buffer as _,
                     params.CurrentDirectory.DosPath.Length as _,
                 )?;
+                let environ = self
+                    .read_process_wchar(params.Environment as _, params.EnvironmentSize as _)
+                    .map(|buf| parse_environment_block(&buf))
+                    .unwrap_or_default();
 
                 Some(ProcParams {
                     argv: cmd_line_to_argv(&cmdline),
                     cwd: wstr_to_path(&cwd),
+                    environ,
                 })
             }
 
@@ -245,10 +578,15 @@ impl LocalProcessInfo {
                     params.CurrentDirectory.DosPath.Buffer as _,
                     params.CurrentDirectory.DosPath.Length as _,
                 )?;
+                let environ = self
+                    .read_process_wchar(params.Environment as _, params.EnvironmentSize as _)
+                    .map(|buf| parse_environment_block(&buf))
+                    .unwrap_or_default();
 
                 Some(ProcParams {
                     argv: cmd_line_to_argv(&cmdline),
                     cwd: wstr_to_path(&cwd),
+                    environ,
                 })
             }
 
@@ -257,7 +595,7 @@ impl LocalProcessInfo {
 
                 let res = unsafe {
                     ReadProcessMemory(
-                        self.0,
+                        self.handle,
                         ptr as _,
                         buf.as_mut_ptr() as _,
                         size,
@@ -271,7 +609,24 @@ impl LocalProcessInfo {
                 Some(buf)
             }
 
-            fn start_time(&self) -> Option<SystemTime> {
+            /// Resolves the account that owns this process, consulting
+            /// `sid_cache` first since a process tree typically shares a
+            /// handful of owners.
+            fn user(&self, sid_cache: &mut HashMap<String, String>) -> Option<String> {
+                let token = TokenHandle::new(self.handle)?;
+                let buf = token.user_sid_buf()?;
+                let token_user = buf.as_ptr() as *const TOKEN_USER;
+                let sid = unsafe { (*token_user).User.Sid };
+                let sid_string = sid_to_string(sid)?;
+                if let Some(cached) = sid_cache.get(&sid_string) {
+                    return Some(cached.clone());
+                }
+                let resolved = lookup_account_sid(sid).unwrap_or_else(|| sid_string.clone());
+                sid_cache.insert(sid_string, resolved.clone());
+                Some(resolved)
+            }
+
+            fn times(&self) -> Option<ProcTimes> {
                 let mut start = FILETIME {
                     dwLowDateTime: 0,
                     dwHighDateTime: 0,
@@ -289,21 +644,66 @@ impl LocalProcessInfo {
                     dwHighDateTime: 0,
                 };
                 let res = unsafe {
-                    GetProcessTimes(self.0, &mut start, &mut exit, &mut kernel, &mut user)
+                    GetProcessTimes(self.handle, &mut start, &mut exit, &mut kernel, &mut user)
                 };
                 if res == 0 {
                     return None;
                 }
 
                 // Units are 100 nanoseconds
-                let start = (start.dwHighDateTime as u64) << 32 | start.dwLowDateTime as u64;
-                let start = Duration::from_nanos(start * 100);
+                fn filetime_to_duration(ft: &FILETIME) -> Duration {
+                    let ticks = (ft.dwHighDateTime as u64) << 32 | ft.dwLowDateTime as u64;
+                    Duration::from_nanos(ticks * 100)
+                }
 
                 // Difference between the windows epoch and the unix epoch
                 const WINDOWS_EPOCH: Duration = Duration::from_secs(11_644_473_600);
 
-                Some(SystemTime::UNIX_EPOCH + start - WINDOWS_EPOCH)
+                Some(ProcTimes {
+                    start: SystemTime::UNIX_EPOCH + filetime_to_duration(&start) - WINDOWS_EPOCH,
+                    kernel: filetime_to_duration(&kernel),
+                    user: filetime_to_duration(&user),
+                })
+            }
+
+            fn memory_info(&self) -> Option<(u64, u64)> {
+                let mut counters: PROCESS_MEMORY_COUNTERS_EX = unsafe { std::mem::zeroed() };
+                counters.cb = std::mem::size_of::<PROCESS_MEMORY_COUNTERS_EX>() as _;
+                let res = unsafe {
+                    GetProcessMemoryInfo(self.handle, &mut counters as *mut _ as _, counters.cb)
+                };
+                if res == 0 {
+                    return None;
+                }
+                Some((counters.WorkingSetSize as u64, counters.PrivateUsage as u64))
+            }
+
+            fn io_counters(&self) -> Option<(u64, u64)> {
+                let mut counters: IO_COUNTERS = unsafe { std::mem::zeroed() };
+                let res = unsafe { GetProcessIoCounters(self.handle, &mut counters) };
+                if res == 0 {
+                    return None;
+                }
+                Some((counters.ReadTransferCount, counters.WriteTransferCount))
+            }
+        }
+
+        struct ProcTimes {
+            start: SystemTime,
+            kernel: Duration,
+            user: Duration,
+        }
+
+        // ProcessCommandLineInformation is only recognized on Windows 8.1
+        // (NT 6.3) and later.
+        fn os_supports_process_command_line_information() -> bool {
+            let mut info: OSVERSIONINFOW = unsafe { std::mem::zeroed() };
+            info.dwOSVersionInfoSize = std::mem::size_of::<OSVERSIONINFOW>() as _;
+            let res = unsafe { RtlGetVersion(&mut info) };
+            if !NT_SUCCESS(res) {
+                return false;
             }
+            (info.dwMajorVersion, info.dwMinorVersion) >= (6, 3)
         }
 
         fn cmd_line_to_argv(buf: &[u16]) -> Vec<String> {
@@ -324,18 +724,46 @@ impl LocalProcessInfo {
             args
         }
 
+        // The environment block is a sequence of NUL-terminated `KEY=VALUE`
+        // strings, terminated by an extra empty (NUL-only) entry.
+        fn parse_environment_block(buf: &[u16]) -> Vec<(String, String)> {
+            let mut environ = vec![];
+            let mut start = 0;
+            while start < buf.len() {
+                match buf[start..].iter().position(|&c| c == 0) {
+                    Some(0) | None => break,
+                    Some(nul) => {
+                        let entry = wstr_to_string(&buf[start..start + nul]);
+                        if let Some(eq) = entry.find('=') {
+                            environ.push((entry[..eq].to_string(), entry[eq + 1..].to_string()));
+                        }
+                        start += nul + 1;
+                    }
+                }
+            }
+            environ
+        }
+
         impl Drop for ProcHandle {
             fn drop(&mut self) {
-                unsafe { CloseHandle(self.0) };
+                unsafe { CloseHandle(self.handle) };
             }
         }
 
-        fn build_proc(info: &PROCESSENTRY32W, procs: &[PROCESSENTRY32W]) -> LocalProcessInfo {
+        fn build_proc(
+            info: &PROCESSENTRY32W,
+            procs: &[PROCESSENTRY32W],
+            threads: &[THREADENTRY32],
+            sid_cache: &mut HashMap<String, String>,
+        ) -> LocalProcessInfo {
             let mut children = HashMap::new();
 
             for kid in procs {
                 if kid.th32ParentProcessID == info.th32ProcessID {
-                    children.insert(kid.th32ProcessID, build_proc(kid, procs));
+                    children.insert(
+                        kid.th32ProcessID,
+                        build_proc(kid, procs, threads, sid_cache),
+                    );
                 }
             }
 
@@ -349,6 +777,14 @@ impl LocalProcessInfo {
             let mut start_time = SystemTime::now();
             let mut cwd = PathBuf::new();
             let mut argv = vec![];
+            let mut environ = vec![];
+            let mut user = None;
+            let mut cpu_kernel_time = Duration::ZERO;
+            let mut cpu_user_time = Duration::ZERO;
+            let mut memory_working_set = 0;
+            let mut memory_private_bytes = 0;
+            let mut disk_read_bytes = 0;
+            let mut disk_write_bytes = 0;
 
             if let Some(proc) = ProcHandle::new(info.th32ProcessID) {
                 if let Some(exe) = proc.executable() {
@@ -357,27 +793,47 @@ impl LocalProcessInfo {
                 if let Some(params) = proc.get_params() {
                     cwd = params.cwd;
                     argv = params.argv;
+                    environ = params.environ;
+                }
+                if let Some(times) = proc.times() {
+                    start_time = times.start;
+                    cpu_kernel_time = times.kernel;
+                    cpu_user_time = times.user;
+                }
+                if let Some((working_set, private_bytes)) = proc.memory_info() {
+                    memory_working_set = working_set;
+                    memory_private_bytes = private_bytes;
                 }
-                if let Some(start) = proc.start_time() {
-                    start_time = start;
+                if let Some((read_bytes, write_bytes)) = proc.io_counters() {
+                    disk_read_bytes = read_bytes;
+                    disk_write_bytes = write_bytes;
                 }
+                user = proc.user(sid_cache);
             }
 
             LocalProcessInfo {
                 pid: info.th32ProcessID,
                 ppid: info.th32ParentProcessID,
                 name,
+                user,
                 executable,
                 cwd,
                 argv,
+                environ,
                 start_time,
-                status: LocalProcessStatus::Run,
+                cpu_kernel_time,
+                cpu_user_time,
+                memory_working_set,
+                memory_private_bytes,
+                disk_read_bytes,
+                disk_write_bytes,
+                status: process_status(info.th32ProcessID, threads),
                 children,
             }
         }
 
         if let Some(info) = procs.iter().find(|info| info.th32ProcessID == pid) {
-            Some(build_proc(info, &procs))
+            Some(build_proc(info, &procs, &threads, &mut HashMap::new()))
         } else {
             None
         }